@@ -0,0 +1,154 @@
+use crate::FloatToInt;
+use crate::TryFromIntError;
+
+// Conversions from f32/f64 to every integer type.
+// Unlike the integer <-> integer TryFrom impls, these cannot defer to `as`
+// and then check the result, because the `float_to_int_unchecked` intrinsic
+// backing such a cast is not usable in const. Instead we truncate toward
+// zero ourselves and compare the truncated value against the target's
+// MIN/MAX expressed in the source float type.
+macro_rules! impl_float_to_int {
+    ($Float: ty, $Int: ty, $doc: expr) => {
+        impl const FloatToInt<$Int> for $Float {
+            #[doc = $doc]
+            #[inline]
+            fn to_int_checked(self) -> Result<$Int, TryFromIntError> {
+                if self.is_nan() || self.is_infinite() {
+                    return Err(TryFromIntError::new());
+                }
+                let truncated = self.trunc();
+                // `<$Int>::MAX as $Float` rounds up to the next representable
+                // value for any `$Int` wider than `$Float`'s mantissa, so it
+                // already equals the exact exclusive upper bound (a power of
+                // two, or infinity for `u128` in `f32`) once `1.0` is added;
+                // for narrower `$Int` where the cast is exact, adding `1.0`
+                // produces that same exact bound. Either way comparing with
+                // `>=` against it is sound, unlike comparing the lossy `MAX`
+                // cast with `>`.
+                let upper = <$Int>::MAX as $Float + 1.0;
+                if truncated < <$Int>::MIN as $Float || truncated >= upper {
+                    Err(TryFromIntError::new())
+                } else {
+                    Ok(truncated as $Int)
+                }
+            }
+
+            #[doc = concat!(
+                "Converts `self` to `", stringify!($Int), "`, saturating at ",
+                "`", stringify!($Int), "::MIN`/`", stringify!($Int), "::MAX` ",
+                "if out of range and rounding NaN to `0`.\n",
+                "\n",
+                "# Examples\n",
+                "\n",
+                "```\n",
+                "use const_traits::FloatToInt;\n",
+                "\n",
+                "assert_eq!(<", stringify!($Float), " as FloatToInt<", stringify!($Int), ">>::to_int_saturating(1 as ", stringify!($Float), "), 1);\n",
+                "assert_eq!(<", stringify!($Float), " as FloatToInt<", stringify!($Int), ">>::to_int_saturating(", stringify!($Float), "::NAN), 0);\n",
+                "assert_eq!(<", stringify!($Float), " as FloatToInt<", stringify!($Int), ">>::to_int_saturating(", stringify!($Float), "::INFINITY), ", stringify!($Int), "::MAX);\n",
+                "assert_eq!(<", stringify!($Float), " as FloatToInt<", stringify!($Int), ">>::to_int_saturating(", stringify!($Float), "::NEG_INFINITY), ", stringify!($Int), "::MIN);\n",
+                "```"
+            )]
+            #[inline]
+            fn to_int_saturating(self) -> $Int {
+                if self.is_nan() {
+                    return 0;
+                }
+                let truncated = self.trunc();
+                // See the matching `upper` bound in `to_int_checked` above:
+                // `<$Int>::MAX as $Float` can itself round up to the next
+                // representable value, so the exclusive upper bound is
+                // `+ 1.0` compared with `>=`, not a bare `>`.
+                let upper = <$Int>::MAX as $Float + 1.0;
+                if truncated < <$Int>::MIN as $Float {
+                    <$Int>::MIN
+                } else if truncated >= upper {
+                    <$Int>::MAX
+                } else {
+                    truncated as $Int
+                }
+            }
+        }
+    };
+    ($Float: ty, $Int: ty) => {
+        impl_float_to_int!(
+            $Float,
+            $Int,
+            concat!(
+                "Converts `",
+                stringify!($Float),
+                "` to `",
+                stringify!($Int),
+                "`, returning an error if `self` is NaN, infinite, or ",
+                "truncates to a value outside the range of `",
+                stringify!($Int),
+                "`.\n",
+                "\n",
+                "# Examples\n",
+                "\n",
+                "```\n",
+                "use const_traits::FloatToInt;\n",
+                "\n",
+                "assert_eq!(<", stringify!($Float), " as FloatToInt<", stringify!($Int), ">>::to_int_checked(1 as ", stringify!($Float), "), Ok(1));\n",
+                "assert!(<", stringify!($Float), " as FloatToInt<", stringify!($Int), ">>::to_int_checked(", stringify!($Float), "::NAN).is_err());\n",
+                "assert!(<", stringify!($Float), " as FloatToInt<", stringify!($Int), ">>::to_int_checked(", stringify!($Float), "::INFINITY).is_err());\n",
+                "```"
+            )
+        );
+    };
+}
+
+// f32 -> integer
+impl_float_to_int! { f32, i8 }
+impl_float_to_int! { f32, i16 }
+// `f32, i32` gets a hand-written doc instead of the generated one above: its
+// boundary is the representative regression case for the off-by-one bug
+// fixed in `to_int_checked` (`i32::MAX as f32` rounds up to 2147483648.0,
+// one past the real maximum).
+impl_float_to_int!(
+    f32,
+    i32,
+    concat!(
+        "Converts `f32` to `i32`, returning an error if `self` is NaN, ",
+        "infinite, or truncates to a value outside the range of `i32`.\n",
+        "\n",
+        "# Examples\n",
+        "\n",
+        "```\n",
+        "use const_traits::FloatToInt;\n",
+        "\n",
+        "assert_eq!(<f32 as FloatToInt<i32>>::to_int_checked(1.5), Ok(1));\n",
+        "assert!(<f32 as FloatToInt<i32>>::to_int_checked(f32::NAN).is_err());\n",
+        "assert!(<f32 as FloatToInt<i32>>::to_int_checked(f32::INFINITY).is_err());\n",
+        "\n",
+        "// `i32::MAX as f32` rounds up to 2147483648.0, one past the real\n",
+        "// maximum value; `to_int_checked` must still reject it.\n",
+        "assert!(<f32 as FloatToInt<i32>>::to_int_checked(2147483648.0).is_err());\n",
+        "// The largest `f32` below that bound is 2147483520.0, which fits.\n",
+        "assert_eq!(<f32 as FloatToInt<i32>>::to_int_checked(2147483520.0), Ok(2147483520));\n",
+        "```"
+    )
+);
+impl_float_to_int! { f32, i64 }
+impl_float_to_int! { f32, i128 }
+impl_float_to_int! { f32, isize }
+impl_float_to_int! { f32, u8 }
+impl_float_to_int! { f32, u16 }
+impl_float_to_int! { f32, u32 }
+impl_float_to_int! { f32, u64 }
+impl_float_to_int! { f32, u128 }
+impl_float_to_int! { f32, usize }
+
+// f64 -> integer
+impl_float_to_int! { f64, i8 }
+impl_float_to_int! { f64, i16 }
+impl_float_to_int! { f64, i32 }
+impl_float_to_int! { f64, i64 }
+impl_float_to_int! { f64, i128 }
+impl_float_to_int! { f64, isize }
+impl_float_to_int! { f64, u8 }
+impl_float_to_int! { f64, u16 }
+impl_float_to_int! { f64, u32 }
+impl_float_to_int! { f64, u64 }
+impl_float_to_int! { f64, u128 }
+impl_float_to_int! { f64, usize }
@@ -142,6 +142,25 @@ impl_from! { u32, f64}
 // Float -> Float
 impl_from! { f32, f64}
 
+// f16/f128 are gated behind their respective cargo features since not every
+// toolchain has the primitive types wired up yet.
+#[cfg(feature = "f16")]
+impl_from! { i8, f16}
+#[cfg(feature = "f16")]
+impl_from! { u8, f16}
+
+#[cfg(feature = "f16")]
+impl_from! { f16, f32}
+#[cfg(feature = "f16")]
+impl_from! { f16, f64}
+#[cfg(all(feature = "f16", feature = "f128"))]
+impl_from! { f16, f128}
+
+#[cfg(feature = "f128")]
+impl_from! { f32, f128}
+#[cfg(feature = "f128")]
+impl_from! { f64, f128}
+
 // bool -> Float
 impl const From<bool> for f32 {
     /// Converts `bool` to `f32` losslessly. The resulting value is positive
@@ -0,0 +1,3 @@
+mod float_to_int;
+mod from;
+pub(crate) mod try_from;
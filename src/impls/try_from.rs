@@ -1,6 +1,65 @@
+use core::convert::Infallible;
+use core::fmt;
+
 use crate::TryFrom;
 
-pub struct TryFromIntError(pub ());
+#[derive_const(Clone, Copy, PartialEq)]
+pub struct TryFromIntError(());
+
+impl TryFromIntError {
+    /// Creates a new `TryFromIntError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_traits::TryFromIntError;
+    ///
+    /// let err = TryFromIntError::new();
+    /// assert_eq!(err, TryFromIntError::new());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self(())
+    }
+
+    /// Returns the message describing this error, the same text used by the
+    /// `Display` impl.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_traits::TryFromIntError;
+    ///
+    /// assert_eq!(
+    ///     TryFromIntError::new().description(),
+    ///     "out of range integral type conversion attempted",
+    /// );
+    /// ```
+    #[inline]
+    pub const fn description(&self) -> &'static str {
+        "out of range integral type conversion attempted"
+    }
+}
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.description().fmt(fmt)
+    }
+}
+
+impl fmt::Debug for TryFromIntError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("TryFromIntError").finish()
+    }
+}
+
+impl core::error::Error for TryFromIntError {}
+
+impl const crate::From<Infallible> for TryFromIntError {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
 
 // no possible bounds violation
 macro_rules! try_from_unbounded {
@@ -33,7 +92,7 @@ macro_rules! try_from_lower_bounded {
                 if u >= 0 {
                     Ok(u as Self)
                 } else {
-                    Err(TryFromIntError(()))
+                    Err(TryFromIntError::new())
                 }
             }
         }
@@ -52,7 +111,7 @@ macro_rules! try_from_upper_bounded {
             #[inline]
             fn try_from(u: $source) -> Result<Self, Self::Error> {
                 if u > (Self::MAX as $source) {
-                    Err(TryFromIntError(()))
+                    Err(TryFromIntError::new())
                 } else {
                     Ok(u as Self)
                 }
@@ -75,7 +134,7 @@ macro_rules! try_from_both_bounded {
                 let min = Self::MIN as $source;
                 let max = Self::MAX as $source;
                 if u < min || u > max {
-                    Err(TryFromIntError(()))
+                    Err(TryFromIntError::new())
                 } else {
                     Ok(u as Self)
                 }
@@ -212,10 +271,9 @@ use core::num::NonZeroU64;
 use core::num::NonZeroU8;
 use core::num::NonZeroUsize;
 
-//FIXME constify this (pretty complicated)
 macro_rules! nzint_impl_try_from_int {
     ($Int: ty, $NonZeroInt: ty, $doc: expr) => {
-        impl TryFrom<$Int> for $NonZeroInt {
+        impl const TryFrom<$Int> for $NonZeroInt {
             type Error = TryFromIntError;
 
             // Rustdocs on the impl block show a "[+] show undocumented items" toggle.
@@ -223,7 +281,12 @@ macro_rules! nzint_impl_try_from_int {
             #[doc = $doc]
             #[inline]
             fn try_from(value: $Int) -> Result<Self, Self::Error> {
-                Self::new(value).ok_or(TryFromIntError(()))
+                if value == 0 {
+                    Err(TryFromIntError::new())
+                } else {
+                    // SAFETY: value is checked to be non-zero above.
+                    Ok(unsafe { Self::new_unchecked(value) })
+                }
             }
         }
     };
@@ -256,10 +319,9 @@ nzint_impl_try_from_int! { i64, NonZeroI64}
 nzint_impl_try_from_int! { i128, NonZeroI128}
 nzint_impl_try_from_int! { isize, NonZeroIsize}
 
-//FIXME constify this (pretty complicated)
 macro_rules! nzint_impl_try_from_nzint {
     ($From:ty => $To:ty, $doc: expr) => {
-        impl TryFrom<$From> for $To {
+        impl const TryFrom<$From> for $To {
             type Error = TryFromIntError;
 
             // Rustdocs on the impl block show a "[+] show undocumented items" toggle.
@@ -267,10 +329,11 @@ macro_rules! nzint_impl_try_from_nzint {
             #[doc = $doc]
             #[inline]
             fn try_from(value: $From) -> Result<Self, Self::Error> {
-                TryFrom::try_from(value.get()).map(|v| {
+                match TryFrom::try_from(value.get()) {
                     // SAFETY: $From is a NonZero type, so v is not zero.
-                    unsafe { Self::new_unchecked(v) }
-                })
+                    Ok(v) => Ok(unsafe { Self::new_unchecked(v) }),
+                    Err(e) => Err(e),
+                }
             }
         }
     };
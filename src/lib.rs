@@ -1,8 +1,16 @@
-#![feature(const_trait_impl, never_type)]
+#![feature(
+    const_trait_impl,
+    never_type,
+    const_float_round_methods,
+    derive_const
+)]
+#![cfg_attr(feature = "f16", feature(f16))]
+#![cfg_attr(feature = "f128", feature(f128))]
 use core::convert::Infallible;
 
 mod impls;
 pub use const_ops::*;
+pub use impls::try_from::TryFromIntError;
 
 #[const_trait]
 pub trait From<T>: Sized {
@@ -22,6 +30,21 @@ pub trait TryInto<T>: Sized {
     type Error;
     fn try_into(self) -> Result<T, Self::Error>;
 }
+/// Checked conversion from a float to an integer type, usable in `const fn`.
+///
+/// The `float_to_int_unchecked` intrinsic this mirrors is not usable in a
+/// const context, so implementors must compute the conversion purely: a
+/// `self` that is NaN, infinite, or truncates outside of `Int`'s range is
+/// rejected rather than producing unspecified behavior.
+#[const_trait]
+pub trait FloatToInt<Int>: Sized {
+    fn to_int_checked(self) -> Result<Int, TryFromIntError>;
+
+    /// Converts `self` to `Int`, saturating at `Int::MIN`/`Int::MAX` if
+    /// `self` is out of range and rounding NaN to `0`, the same total,
+    /// panic-free semantics as an `as` cast.
+    fn to_int_saturating(self) -> Int;
+}
 
 impl<T, U> const Into<U> for T
 where